@@ -1,17 +1,32 @@
 use regex::Regex;
+use std::collections::VecDeque;
 use std::env;
-use std::io::{self, BufRead, BufReader, Write};
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
 use std::process::{Command, Stdio};
-use std::sync::mpsc;
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
-use termion::{clear, terminal_size};
+use termion::{clear, cursor, terminal_size};
 
 fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
 
+    // Multi-command mode: `::`-separated job groups or a `--jobs` file turn
+    // oneline into a small concurrent supervisor with a live dashboard.
+    if args.iter().any(|a| a == "::" || a == "--jobs") {
+        return run_multi(&args[1..]);
+    }
+
     // Parse arguments
     let mut label = String::new();
+    let mut use_pty = false;
+    let mut pty_size: Option<(u16, u16)> = None; // (cols, rows)
+    let mut head_limit = 1000;
+    let mut tail_limit = 1000;
+    let mut log_path: Option<String> = None;
+    let mut log_strip_ansi = false;
+    let mut json = false;
     let mut command_pos = 1;
 
     let mut i = 1;
@@ -27,6 +42,72 @@ fn main() -> io::Result<()> {
                     std::process::exit(1);
                 }
             }
+            "--pty" => {
+                use_pty = true;
+                i += 1;
+                command_pos += 1;
+            }
+            "--pty-size" => {
+                if i + 1 < args.len() {
+                    match parse_pty_size(&args[i + 1]) {
+                        Ok(size) => pty_size = Some(size),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                    command_pos += 2;
+                } else {
+                    eprintln!("Error: --pty-size requires a value like 80x24 (cols x rows)");
+                    std::process::exit(1);
+                }
+            }
+            "--log" => {
+                if i + 1 < args.len() {
+                    log_path = Some(args[i + 1].clone());
+                    i += 2;
+                    command_pos += 2;
+                } else {
+                    eprintln!("Error: --log requires a path");
+                    std::process::exit(1);
+                }
+            }
+            "--log-strip-ansi" => {
+                log_strip_ansi = true;
+                i += 1;
+                command_pos += 1;
+            }
+            "--json" => {
+                json = true;
+                i += 1;
+                command_pos += 1;
+            }
+            "--head" | "--tail" | "--max-captured-lines" => {
+                let opt = args[i].clone();
+                if i + 1 >= args.len() {
+                    eprintln!("Error: {} requires a numeric value", opt);
+                    std::process::exit(1);
+                }
+                let value: usize = match args[i + 1].parse() {
+                    Ok(v) => v,
+                    Err(_) => {
+                        eprintln!("Error: {} requires a numeric value", opt);
+                        std::process::exit(1);
+                    }
+                };
+                match opt.as_str() {
+                    "--head" => head_limit = value,
+                    "--tail" => tail_limit = value,
+                    // Split a single budget evenly between head and tail.
+                    _ => {
+                        head_limit = value / 2;
+                        tail_limit = value - head_limit;
+                    }
+                }
+                i += 2;
+                command_pos += 2;
+            }
             x => {
                 if x.starts_with("-") {
                     eprintln!("Error: Unknown option: {}", x);
@@ -38,9 +119,16 @@ fn main() -> io::Result<()> {
         }
     }
 
+    // `--pty-size` only has meaning for the PTY path; reject it otherwise
+    // rather than silently ignoring the requested size.
+    if pty_size.is_some() && !use_pty {
+        eprintln!("Error: --pty-size requires --pty");
+        std::process::exit(1);
+    }
+
     // Check if we have enough arguments for a command
     if command_pos >= args.len() {
-        eprintln!("Usage: {} [--label \"Label\"] command [args...]", args[0]);
+        eprintln!("Usage: {} [--label \"Label\"] [--pty] command [args...]", args[0]);
         eprintln!("Example: {} --label \"Building Project\" make all", args[0]);
         std::process::exit(1);
     }
@@ -58,58 +146,15 @@ fn main() -> io::Result<()> {
         }
     }
 
-    let command_name = &args[command_pos];
-    let command_args = &args[(command_pos + 1)..];
+    let command_name = args[command_pos].clone();
+    let command_args: Vec<String> = args[(command_pos + 1)..].to_vec();
 
-    // Store stdout and stderr content in memory
-    let stdout_content = Arc::new(Mutex::new(Vec::<String>::new()));
-    let stderr_content = Arc::new(Mutex::new(Vec::<String>::new()));
+    // Store stdout and stderr content in bounded in-memory buffers
+    let stdout_content = Arc::new(Mutex::new(CaptureBuffer::new(head_limit, tail_limit)));
+    let stderr_content = Arc::new(Mutex::new(CaptureBuffer::new(head_limit, tail_limit)));
 
     // Get terminal width
-    let (term_width, _) = terminal_size().unwrap_or((80, 24));
-
-    // Set environment variables for forcing color output
-    let mut command = Command::new(command_name);
-    command
-        .args(command_args)
-        .env("TERM", "xterm-256color")
-        .env("FORCE_COLOR", "1")
-        .env("CLICOLOR_FORCE", "1")
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-
-    let mut child = match command.spawn() {
-        Ok(child) => child,
-        Err(e) => {
-            match e.downcast::<std::io::Error>() {
-                Ok(e) => {
-                    if e.kind() == std::io::ErrorKind::NotFound {
-                        eprintln!("Command not found: {command_name}");
-                    } else {
-                        eprintln!("Error: {}", e);
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error: Failed to start command: {e}");
-                }
-            }
-            std::process::exit(1);
-        }
-    };
-
-    // Set up pipes for stdout and stderr
-    let stdout_pipe = child
-        .stdout
-        .take()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to capture stdout"))?;
-    let stderr_pipe = child
-        .stderr
-        .take()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to capture stderr"))?;
-
-    // Clone Arc references for threads
-    let stdout_content_clone = Arc::clone(&stdout_content);
-    let stderr_content_clone = Arc::clone(&stderr_content);
+    let (term_width, term_height) = terminal_size().unwrap_or((80, 24));
 
     // Create regex for stripping ANSI escape sequences
     let ansi_regex = Regex::new(
@@ -119,36 +164,27 @@ fn main() -> io::Result<()> {
     let line_modifying_regex = Regex::new(r"(\r)|(\x1b\[K)|(\x1b\[1K)|(\x1b\[2K)|(\x1b\[[0-9]*G)|(\x1b\[[0-9]*C)|(\x1b\[[0-9]*D)|(\x1b\[s)|(\x1b\[u)|(\b)").unwrap();
     let prefix = format!("[{}] ", label);
 
-    // Create a channel for interleaved output
-    let (tx_stdout, rx) = mpsc::channel();
-    let tx_stderr = tx_stdout.clone();
-
-    // Thread for processing stdout
-    let stdout_thread = thread::spawn(move || {
-        let reader = BufReader::new(stdout_pipe);
-        for line in reader.lines().map_while(Result::ok) {
-            // Store the line
-            if let Ok(mut content) = stdout_content_clone.lock() {
-                content.push(line.clone());
-            }
-
-            // Send to channel for display
-            let _ = tx_stdout.send(("stdout".to_string(), line));
+    // Open the optional log tee up front so we fail fast on a bad path.
+    let log_sink = match log_path {
+        Some(path) => {
+            let file = match File::create(&path) {
+                Ok(file) => file,
+                Err(e) => {
+                    eprintln!("Error: Failed to open log file {path}: {e}");
+                    std::process::exit(1);
+                }
+            };
+            Some(Arc::new(Mutex::new(LogSink {
+                writer: BufWriter::new(file),
+                strip_ansi: log_strip_ansi,
+                ansi: ansi_regex.clone(),
+            })))
         }
-    });
-
-    // Thread for capturing stderr
-    let stderr_thread = thread::spawn(move || {
-        let reader = BufReader::new(stderr_pipe);
-        for line in reader.lines().map_while(Result::ok) {
-            if let Ok(mut content) = stderr_content_clone.lock() {
-                content.push(line.clone());
-            }
+        None => None,
+    };
 
-            // Send to channel for display if --stderr is enabled
-            let _ = tx_stderr.send(("stderr".to_string(), line));
-        }
-    });
+    // Create a channel for interleaved output
+    let (tx_stdout, rx) = mpsc::channel::<(String, String)>();
 
     // Thread for displaying output from both streams
     let display_thread = thread::spawn(move || {
@@ -156,6 +192,11 @@ fn main() -> io::Result<()> {
 
         // Process messages from both stdout and stderr
         while let Ok((_, line)) = rx.recv() {
+            // In --json mode we suppress the live view and only collect output.
+            if json {
+                continue;
+            }
+
             // Skip empty lines
             if line.is_empty() {
                 continue;
@@ -175,12 +216,29 @@ fn main() -> io::Result<()> {
         printed_anything
     });
 
-    // Wait for child process to complete
-    let status = child.wait()?;
+    // Run the command, either through a pseudo-terminal or with plain pipes.
+    let status = if use_pty {
+        let (cols, rows) = pty_size.unwrap_or((term_width, term_height));
+        run_pty(
+            &command_name,
+            &command_args,
+            cols,
+            rows,
+            tx_stdout,
+            Arc::clone(&stdout_content),
+            log_sink.clone(),
+        )?
+    } else {
+        run_piped(
+            &command_name,
+            &command_args,
+            tx_stdout,
+            Arc::clone(&stdout_content),
+            Arc::clone(&stderr_content),
+            log_sink.clone(),
+        )?
+    };
 
-    // Wait for threads to finish
-    let _ = stdout_thread.join();
-    let _ = stderr_thread.join();
     let printed = display_thread.join().unwrap_or(false);
 
     if printed {
@@ -188,46 +246,448 @@ fn main() -> io::Result<()> {
         println!();
     }
 
-    if status.success() {
+    // In --json mode emit a structured result instead of the human display,
+    // but still mirror the child's exit code so `&&` chaining keeps working.
+    if json {
+        let command = std::iter::once(command_name.as_str())
+            .chain(command_args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ");
+        emit_json_result(
+            &label,
+            &command,
+            status.code,
+            &stdout_content,
+            &stderr_content,
+        );
+        if status.success {
+            return Ok(());
+        }
+        flush_log(&log_sink);
+        std::process::exit(status.code.unwrap_or(1));
+    }
+
+    if status.success {
         return Ok(());
     }
 
     eprintln!(
         "Error: Command failed with exit code {}",
-        status.code().unwrap_or(-1)
+        status.code.unwrap_or(-1)
     );
     eprintln!("Error output:");
 
-    // Print stderr content
+    // Print stderr content (head, an omitted-lines marker, then tail)
     let mut printed_stderr = false;
     if let Ok(content) = stderr_content.lock() {
-        for line in content.iter() {
-            eprintln!("{}", line);
+        if !content.is_empty() {
+            for line in content.dump() {
+                eprintln!("{}", line);
+            }
             printed_stderr = true;
         }
     }
     if !printed_stderr {
         if let Ok(content) = stdout_content.lock() {
-            for line in content.iter() {
+            for line in content.dump() {
                 eprintln!("{}", line);
             }
         }
     }
 
-    std::process::exit(status.code().unwrap_or(1));
+    flush_log(&log_sink);
+    std::process::exit(status.code.unwrap_or(1));
 }
 
-// Process and display a single line of output
-fn process_output_line(
+// The outcome of running a child, decoupled from how it was spawned.
+struct RunStatus {
+    success: bool,
+    code: Option<i32>,
+}
+
+// A tee of the full raw output to a file, written incrementally as lines
+// stream in. ANSI escapes are preserved by default, or stripped with
+// `--log-strip-ansi` for a plain-text transcript.
+struct LogSink {
+    writer: BufWriter<File>,
+    strip_ansi: bool,
+    ansi: Regex,
+}
+
+impl LogSink {
+    // Append a line to the buffered writer. Flushing is left to the
+    // `BufWriter` (and the `Drop` impl at EOF), so a command emitting many
+    // `\r` progress chunks doesn't cost one syscall per chunk.
+    fn write_line(&mut self, line: &str) {
+        if self.strip_ansi {
+            let clean = self.ansi.replace_all(line, "");
+            let _ = writeln!(self.writer, "{}", clean);
+        } else {
+            let _ = writeln!(self.writer, "{}", line);
+        }
+    }
+
+    fn flush(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+impl Drop for LogSink {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+// Flush the optional log tee. `Drop` handles the normal return paths, but the
+// `process::exit` paths (command failure, `--json`) skip destructors, so the
+// buffered tail of the transcript must be flushed explicitly before exiting.
+fn flush_log(log: &Option<Arc<Mutex<LogSink>>>) {
+    if let Some(log) = log {
+        if let Ok(mut log) = log.lock() {
+            log.flush();
+        }
+    }
+}
+
+// Bounded in-memory capture: keeps the first `head_limit` lines and the last
+// `tail_limit` lines, counting how many were dropped in between. This keeps
+// the most useful context (the start of the run and the error near the end)
+// without growing without bound on commands that print hundreds of MB.
+struct CaptureBuffer {
+    head: Vec<String>,
+    tail: VecDeque<String>,
+    head_limit: usize,
+    tail_limit: usize,
+    dropped: usize,
+}
+
+impl CaptureBuffer {
+    fn new(head_limit: usize, tail_limit: usize) -> Self {
+        CaptureBuffer {
+            head: Vec::new(),
+            tail: VecDeque::new(),
+            head_limit,
+            tail_limit,
+            dropped: 0,
+        }
+    }
+
+    fn push(&mut self, line: String) {
+        if self.head.len() < self.head_limit {
+            self.head.push(line);
+            return;
+        }
+        if self.tail_limit == 0 {
+            self.dropped += 1;
+            return;
+        }
+        self.tail.push_back(line);
+        if self.tail.len() > self.tail_limit {
+            self.tail.pop_front();
+            self.dropped += 1;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_empty() && self.tail.is_empty()
+    }
+
+    // The captured lines with a marker line standing in for the omitted middle.
+    // Intended for the human-facing failure dump, not machine output.
+    fn dump(&self) -> Vec<String> {
+        let mut out = self.head.clone();
+        if self.dropped > 0 {
+            out.push(format!("… {} lines omitted …", self.dropped));
+        }
+        out.extend(self.tail.iter().cloned());
+        out
+    }
+
+    // The raw captured head+tail lines with no prose marker spliced in, for
+    // machine-readable output. Omissions are reported separately via `dropped`.
+    fn raw_lines(&self) -> Vec<String> {
+        let mut out = self.head.clone();
+        out.extend(self.tail.iter().cloned());
+        out
+    }
+
+    // How many lines were dropped from the middle of the capture.
+    fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+// Parse a `COLSxROWS` size string into `(cols, rows)`. Both axes must be
+// present and non-zero; anything else is rejected so a malformed spec never
+// reaches `openpty` as a `0`-sized dimension.
+fn parse_pty_size(spec: &str) -> Result<(u16, u16), String> {
+    let err = || format!("--pty-size expects a value like 80x24 (cols x rows), got '{spec}'");
+    let mut parts = spec.split(['x', 'X']);
+    let cols = parts.next().ok_or_else(err)?.trim();
+    let rows = parts.next().ok_or_else(err)?.trim();
+    if parts.next().is_some() {
+        return Err(err());
+    }
+    let cols: u16 = cols.parse().map_err(|_| err())?;
+    let rows: u16 = rows.parse().map_err(|_| err())?;
+    if cols == 0 || rows == 0 {
+        return Err(err());
+    }
+    Ok((cols, rows))
+}
+
+// Pump a reader into the display channel, splitting on both `\n` and `\r` so
+// that each progress-bar overwrite becomes its own status update. Each logical
+// line is captured in memory for the failure dump and sent down the channel. A
+// trailing chunk with no delimiter is flushed at EOF.
+fn pump_stream<R: Read + Send + 'static>(
+    reader: R,
+    stream: &'static str,
+    tx: Sender<(String, String)>,
+    content: Arc<Mutex<CaptureBuffer>>,
+    log: Option<Arc<Mutex<LogSink>>>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut reader = reader;
+        let mut pending: Vec<u8> = Vec::new();
+        let mut chunk = [0u8; 8192];
+
+        let emit = |bytes: &[u8]| {
+            let line = String::from_utf8_lossy(bytes).into_owned();
+            if let Ok(mut content) = content.lock() {
+                content.push(line.clone());
+            }
+            if let Some(log) = &log {
+                if let Ok(mut log) = log.lock() {
+                    log.write_line(&line);
+                }
+            }
+            let _ = tx.send((stream.to_string(), line));
+        };
+
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => {
+                    pending.extend_from_slice(&chunk[..n]);
+                    while let Some(idx) = memchr::memchr2(b'\n', b'\r', &pending) {
+                        // A trailing `\r` might be the first half of a `\r\n`
+                        // split across reads; wait for the next chunk before
+                        // deciding so we don't emit a spurious empty line.
+                        if pending[idx] == b'\r' && idx + 1 == pending.len() {
+                            break;
+                        }
+                        // Collapse a `\r\n` pair into one boundary; a bare `\r`
+                        // (progress overwrite) or `\n` each ends a logical line.
+                        let consumed = if pending[idx] == b'\r'
+                            && pending.get(idx + 1) == Some(&b'\n')
+                        {
+                            idx + 2
+                        } else {
+                            idx + 1
+                        };
+                        emit(&pending[..idx]);
+                        pending.drain(..consumed);
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+
+        // At EOF a held-back trailing `\r` can no longer start a `\r\n`, so it
+        // is a plain line boundary like any other.
+        if pending.last() == Some(&b'\r') {
+            pending.pop();
+        }
+        if !pending.is_empty() {
+            emit(&pending);
+        }
+    })
+}
+
+// Spawn the command with piped stdout/stderr, coaxing color out of programs
+// via the usual environment variables. This is the default path and behaves
+// well in non-TTY environments like CI.
+fn run_piped(
+    command_name: &str,
+    command_args: &[String],
+    tx: Sender<(String, String)>,
+    stdout_content: Arc<Mutex<CaptureBuffer>>,
+    stderr_content: Arc<Mutex<CaptureBuffer>>,
+    log: Option<Arc<Mutex<LogSink>>>,
+) -> io::Result<RunStatus> {
+    let mut command = Command::new(command_name);
+    command
+        .args(command_args)
+        .env("TERM", "xterm-256color")
+        .env("FORCE_COLOR", "1")
+        .env("CLICOLOR_FORCE", "1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                eprintln!("Command not found: {command_name}");
+            } else {
+                eprintln!("Error: {}", e);
+            }
+            std::process::exit(1);
+        }
+    };
+
+    let stdout_pipe = child
+        .stdout
+        .take()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to capture stdout"))?;
+    let stderr_pipe = child
+        .stderr
+        .take()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to capture stderr"))?;
+
+    let tx_stderr = tx.clone();
+    let stdout_thread = pump_stream(stdout_pipe, "stdout", tx, stdout_content, log.clone());
+    let stderr_thread = pump_stream(stderr_pipe, "stderr", tx_stderr, stderr_content, log);
+
+    let status = child.wait()?;
+
+    let _ = stdout_thread.join();
+    let _ = stderr_thread.join();
+
+    Ok(RunStatus {
+        success: status.success(),
+        code: status.code(),
+    })
+}
+
+// Spawn the command through a pseudo-terminal sized to the current terminal
+// (or an explicit override). Programs see a real TTY, so they keep color,
+// spinners and progress bars enabled. The PTY merges stdout and stderr into
+// a single stream, which we feed through the same pipeline.
+fn run_pty(
+    command_name: &str,
+    command_args: &[String],
+    cols: u16,
+    rows: u16,
+    tx: Sender<(String, String)>,
+    stdout_content: Arc<Mutex<CaptureBuffer>>,
+    log: Option<Arc<Mutex<LogSink>>>,
+) -> io::Result<RunStatus> {
+    use portable_pty::{CommandBuilder, PtySize, native_pty_system};
+
+    let pty_system = native_pty_system();
+    let pair = pty_system
+        .openpty(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    let mut cmd = CommandBuilder::new(command_name);
+    cmd.args(command_args);
+
+    let mut child = match pair.slave.spawn_command(cmd) {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("Command not found: {command_name} ({e})");
+            std::process::exit(1);
+        }
+    };
+
+    // Drop the slave so that EOF propagates to the reader once the child exits.
+    drop(pair.slave);
+
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    let pump_thread = pump_stream(reader, "stdout", tx, stdout_content, log);
+
+    let status = child
+        .wait()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    // Release the master so the reader thread sees EOF, then join it.
+    drop(pair.master);
+    let _ = pump_thread.join();
+
+    Ok(RunStatus {
+        success: status.success(),
+        code: Some(status.exit_code() as i32),
+    })
+}
+
+// Escape a string for inclusion in a JSON document.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// Render a list of lines as a JSON string array.
+fn json_array(lines: &[String]) -> String {
+    let items: Vec<String> = lines
+        .iter()
+        .map(|l| format!("\"{}\"", json_escape(l)))
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+// Emit the machine-readable result for --json mode to stdout.
+fn emit_json_result(
+    label: &str,
+    command: &str,
+    code: Option<i32>,
+    stdout_content: &Arc<Mutex<CaptureBuffer>>,
+    stderr_content: &Arc<Mutex<CaptureBuffer>>,
+) {
+    let (stdout_lines, stdout_dropped) = stdout_content
+        .lock()
+        .map(|c| (c.raw_lines(), c.dropped()))
+        .unwrap_or_default();
+    let (stderr_lines, stderr_dropped) = stderr_content
+        .lock()
+        .map(|c| (c.raw_lines(), c.dropped()))
+        .unwrap_or_default();
+    let code = code.map(|c| c.to_string()).unwrap_or_else(|| "null".to_string());
+    println!(
+        "{{\"label\":\"{}\",\"command\":\"{}\",\"exit_code\":{},\"stdout\":{},\"stdout_dropped\":{},\"stderr\":{},\"stderr_dropped\":{}}}",
+        json_escape(label),
+        json_escape(command),
+        code,
+        json_array(&stdout_lines),
+        stdout_dropped,
+        json_array(&stderr_lines),
+        stderr_dropped,
+    );
+}
+
+// Collapse a line of output into its prefixed, width-bounded display form,
+// or `None` if there is nothing to show. Shared by the single-command view
+// and the multi-command dashboard.
+fn format_output_line(
     prefix: &str,
     line: &str,
     ansi_regex: &Regex,
     line_modifying_regex: &Regex,
     term_width: u16,
-) {
+) -> Option<String> {
     // Skip empty or duplicate lines
     if line.is_empty() {
-        return;
+        return None;
     }
     let prefix_len = prefix.len();
 
@@ -245,10 +705,354 @@ fn process_output_line(
         line.clone()
     };
 
-    // Clear line and print with prefix
-    print!("\r{}", clear::CurrentLine);
-    print!("{prefix}{display_line}");
-    let _ = io::stdout().flush();
+    Some(format!("{prefix}{display_line}"))
+}
+
+// Process and display a single line of output
+fn process_output_line(
+    prefix: &str,
+    line: &str,
+    ansi_regex: &Regex,
+    line_modifying_regex: &Regex,
+    term_width: u16,
+) {
+    if let Some(display) = format_output_line(prefix, line, ansi_regex, line_modifying_regex, term_width)
+    {
+        // Clear line and print in place
+        print!("\r{}", clear::CurrentLine);
+        print!("{display}");
+        let _ = io::stdout().flush();
+    }
+}
+
+// A single labeled command in multi-command mode.
+struct Job {
+    label: String,
+    name: String,
+    args: Vec<String>,
+}
+
+// The result of running one job, used for the final summary.
+struct JobResult {
+    index: usize,
+    success: bool,
+    code: Option<i32>,
+}
+
+// A counting semaphore used to cap how many jobs run at once.
+struct Semaphore {
+    state: Mutex<usize>,
+    cond: Condvar,
+}
+
+impl Semaphore {
+    fn new(permits: usize) -> Self {
+        Semaphore {
+            state: Mutex::new(permits),
+            cond: Condvar::new(),
+        }
+    }
+
+    fn acquire(&self) {
+        let mut permits = self.state.lock().unwrap();
+        while *permits == 0 {
+            permits = self.cond.wait(permits).unwrap();
+        }
+        *permits -= 1;
+    }
+
+    fn release(&self) {
+        let mut permits = self.state.lock().unwrap();
+        *permits += 1;
+        self.cond.notify_one();
+    }
+}
+
+// A live dashboard that owns stdout and keeps one fixed row per job, updating
+// each in place. Rows are reserved up front; updates hop to a row with a
+// saved/restored cursor so the terminal cursor always parks below the block.
+struct Dashboard {
+    rows: usize,
+}
+
+impl Dashboard {
+    fn new(rows: usize) -> Self {
+        // Reserve one line per job; the cursor ends just below the block.
+        for _ in 0..rows {
+            println!();
+        }
+        Dashboard { rows }
+    }
+
+    fn set_row(&self, index: usize, text: &str) {
+        let up = (self.rows - index) as u16;
+        print!("{}", cursor::Save);
+        print!("{}", cursor::Up(up));
+        print!("\r{}", clear::CurrentLine);
+        print!("{text}");
+        print!("{}", cursor::Restore);
+        let _ = io::stdout().flush();
+    }
+}
+
+// Derive a label from command parts the same way single-command mode does.
+fn derive_label(parts: &[String]) -> String {
+    let mut label = parts
+        .iter()
+        .take_while(|s| !s.starts_with('-'))
+        .map(|s| s.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if label.chars().count() > 32 {
+        label = format!("{}…", &label.chars().take(32).collect::<String>());
+    }
+    label
+}
+
+// Build a job from a `[--label L] [--] cmd args...` token group.
+fn job_from_tokens(tokens: &[String]) -> Option<Job> {
+    let mut label = String::new();
+    let mut j = 0;
+    if tokens.first().map(String::as_str) == Some("--label") {
+        label = tokens.get(1).cloned().unwrap_or_default();
+        j = 2;
+    }
+    if tokens.get(j).map(String::as_str) == Some("--") {
+        j += 1;
+    }
+    let name = tokens.get(j)?.clone();
+    let args = tokens[(j + 1)..].to_vec();
+    if label.is_empty() {
+        label = derive_label(&tokens[j..]);
+    }
+    Some(Job { label, name, args })
+}
+
+// Split `::`-separated token groups into jobs.
+fn parse_job_groups(tokens: &[String]) -> Vec<Job> {
+    tokens
+        .split(|t| t == "::")
+        .filter(|group| !group.is_empty())
+        .filter_map(job_from_tokens)
+        .collect()
+}
+
+// Parse a jobs file: one job per line as `Label | command args`, or just a
+// command (label derived) when no `|` is present. Blank lines and lines
+// starting with `#` are ignored.
+fn parse_jobs_file(path: &str) -> io::Result<Vec<Job>> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut jobs = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (label, command) = match line.split_once('|') {
+            Some((l, c)) => (l.trim().to_string(), c.trim()),
+            None => (String::new(), line),
+        };
+        let mut parts = command.split_whitespace().map(str::to_string);
+        let Some(name) = parts.next() else {
+            continue;
+        };
+        let args: Vec<String> = parts.collect();
+        let label = if label.is_empty() {
+            let mut all = vec![name.clone()];
+            all.extend(args.clone());
+            derive_label(&all)
+        } else {
+            label
+        };
+        jobs.push(Job { label, name, args });
+    }
+    Ok(jobs)
+}
+
+// Run one job to completion, streaming its latest collapsed line to `row`.
+fn run_job(index: usize, job: &Job, term_width: u16, row: Sender<(usize, String)>) -> JobResult {
+    let ansi_regex = Regex::new(
+        r"\x1B(?:\][0-9;]*(?:;|;{2}).*?(?:\x07|\x1B\\)|[\[0-9;]*[a-zA-Z])|\x07|\xe2\x80\xa6",
+    )
+    .unwrap();
+    let line_modifying_regex = Regex::new(r"(\r)|(\x1b\[K)|(\x1b\[1K)|(\x1b\[2K)|(\x1b\[[0-9]*G)|(\x1b\[[0-9]*C)|(\x1b\[[0-9]*D)|(\x1b\[s)|(\x1b\[u)|(\b)").unwrap();
+    let prefix = format!("[{}] ", job.label);
+
+    let mut command = Command::new(&job.name);
+    command
+        .args(&job.args)
+        .env("TERM", "xterm-256color")
+        .env("FORCE_COLOR", "1")
+        .env("CLICOLOR_FORCE", "1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(_) => {
+            let _ = row.send((index, format!("{prefix}<command not found: {}>", job.name)));
+            return JobResult {
+                index,
+                success: false,
+                code: None,
+            };
+        }
+    };
+
+    let stdout_pipe = child.stdout.take();
+    let stderr_pipe = child.stderr.take();
+
+    // Bound per-job memory even though the dashboard only shows the latest line.
+    let capture = Arc::new(Mutex::new(CaptureBuffer::new(1000, 1000)));
+    let (tx, rx) = mpsc::channel();
+    let mut pumps = Vec::new();
+    if let Some(pipe) = stdout_pipe {
+        pumps.push(pump_stream(pipe, "stdout", tx.clone(), Arc::clone(&capture), None));
+    }
+    if let Some(pipe) = stderr_pipe {
+        pumps.push(pump_stream(pipe, "stderr", tx, Arc::clone(&capture), None));
+    } else {
+        drop(tx);
+    }
+
+    for (_, line) in rx {
+        if let Some(display) =
+            format_output_line(&prefix, &line, &ansi_regex, &line_modifying_regex, term_width)
+        {
+            let _ = row.send((index, display));
+        }
+    }
+
+    let status = child.wait();
+    for pump in pumps {
+        let _ = pump.join();
+    }
+
+    match status {
+        Ok(status) => JobResult {
+            index,
+            success: status.success(),
+            code: status.code(),
+        },
+        Err(_) => JobResult {
+            index,
+            success: false,
+            code: None,
+        },
+    }
+}
+
+// Supervisor entry point: parse jobs, run them concurrently under a dashboard
+// and print a per-command summary. Returns non-zero if any job failed.
+fn run_multi(tokens: &[String]) -> io::Result<()> {
+    let mut max_parallel: Option<usize> = None;
+    let mut jobs_file: Option<String> = None;
+    let mut rest: Vec<String> = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i].as_str() {
+            "--max-parallel" => {
+                max_parallel = tokens.get(i + 1).and_then(|v| v.parse().ok());
+                if max_parallel.is_none() {
+                    eprintln!("Error: --max-parallel requires a numeric value");
+                    std::process::exit(1);
+                }
+                i += 2;
+            }
+            "--jobs" => {
+                jobs_file = tokens.get(i + 1).cloned();
+                if jobs_file.is_none() {
+                    eprintln!("Error: --jobs requires a path");
+                    std::process::exit(1);
+                }
+                i += 2;
+            }
+            _ => {
+                rest.push(tokens[i].clone());
+                i += 1;
+            }
+        }
+    }
+
+    let mut jobs = Vec::new();
+    if let Some(path) = jobs_file {
+        jobs.extend(parse_jobs_file(&path)?);
+    }
+    jobs.extend(parse_job_groups(&rest));
+
+    if jobs.is_empty() {
+        eprintln!("Error: no jobs to run");
+        std::process::exit(1);
+    }
+
+    let (term_width, _) = terminal_size().unwrap_or((80, 24));
+    let permits = max_parallel.unwrap_or(jobs.len()).max(1);
+    let semaphore = Arc::new(Semaphore::new(permits));
+
+    // A single render thread owns the dashboard and stdout.
+    let dashboard = Dashboard::new(jobs.len());
+    let (row_tx, row_rx) = mpsc::channel::<(usize, String)>();
+    let render_thread = thread::spawn(move || {
+        while let Ok((index, line)) = row_rx.recv() {
+            dashboard.set_row(index, &line);
+        }
+    });
+
+    let jobs = Arc::new(jobs);
+    let mut handles = Vec::new();
+    for index in 0..jobs.len() {
+        let jobs = Arc::clone(&jobs);
+        let semaphore = Arc::clone(&semaphore);
+        let row_tx = row_tx.clone();
+        handles.push(thread::spawn(move || {
+            semaphore.acquire();
+            let result = run_job(index, &jobs[index], term_width, row_tx);
+            semaphore.release();
+            result
+        }));
+    }
+    drop(row_tx);
+
+    // A job thread is spawned per index in order, so a panicked thread maps
+    // back to its job; record it as a failure rather than letting it vanish
+    // from the summary (which would wrongly let oneline exit 0).
+    let mut results: Vec<JobResult> = handles
+        .into_iter()
+        .enumerate()
+        .map(|(index, h)| {
+            h.join().unwrap_or(JobResult {
+                index,
+                success: false,
+                code: None,
+            })
+        })
+        .collect();
+    let _ = render_thread.join();
+    results.sort_by_key(|r| r.index);
+
+    // Final summary below the dashboard.
+    println!();
+    let mut any_failed = false;
+    for result in &results {
+        let label = &jobs[result.index].label;
+        match result.code {
+            Some(code) if result.success => println!("[{label}] exited {code}"),
+            Some(code) => {
+                any_failed = true;
+                println!("[{label}] failed with exit code {code}");
+            }
+            None => {
+                any_failed = true;
+                println!("[{label}] failed to run");
+            }
+        }
+    }
+
+    if any_failed {
+        std::process::exit(1);
+    }
+    Ok(())
 }
 
 // Function to truncate string with ANSI escape sequences
@@ -290,3 +1094,88 @@ fn truncate_with_ansi(input: &str, max_len: usize) -> String {
 
     truncated
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pty_size_accepts_cols_x_rows() {
+        assert_eq!(parse_pty_size("80x24").unwrap(), (80, 24));
+        assert_eq!(parse_pty_size("120X40").unwrap(), (120, 40));
+        assert_eq!(parse_pty_size(" 80 x 24 ").unwrap(), (80, 24));
+    }
+
+    #[test]
+    fn parse_pty_size_rejects_malformed() {
+        assert!(parse_pty_size("80x").is_err());
+        assert!(parse_pty_size("x24").is_err());
+        assert!(parse_pty_size("junk").is_err());
+        assert!(parse_pty_size("0x24").is_err());
+        assert!(parse_pty_size("80x0").is_err());
+        assert!(parse_pty_size("80x24x2").is_err());
+    }
+
+    fn collect_lines(input: &[u8]) -> Vec<String> {
+        let (tx, rx) = mpsc::channel::<(String, String)>();
+        let content = Arc::new(Mutex::new(CaptureBuffer::new(1000, 1000)));
+        let handle = pump_stream(io::Cursor::new(input.to_vec()), "stdout", tx, content, None);
+        handle.join().unwrap();
+        rx.into_iter().map(|(_, line)| line).collect()
+    }
+
+    #[test]
+    fn pump_stream_splits_on_newline_and_carriage_return() {
+        assert_eq!(collect_lines(b"a\nb\rc\r\nd"), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn pump_stream_flushes_trailing_chunk_without_delimiter() {
+        assert_eq!(collect_lines(b"no newline"), vec!["no newline"]);
+    }
+
+    #[test]
+    fn pump_stream_does_not_emit_spurious_line_for_crlf() {
+        assert_eq!(collect_lines(b"x\r\n"), vec!["x"]);
+    }
+
+    #[test]
+    fn capture_buffer_keeps_head_and_tail_with_dropped_count() {
+        let mut buf = CaptureBuffer::new(2, 2);
+        for i in 0..10 {
+            buf.push(format!("line{i}"));
+        }
+        assert_eq!(buf.raw_lines(), vec!["line0", "line1", "line8", "line9"]);
+        assert_eq!(buf.dropped(), 6);
+        assert_eq!(
+            buf.dump(),
+            vec!["line0", "line1", "… 6 lines omitted …", "line8", "line9"]
+        );
+    }
+
+    #[test]
+    fn capture_buffer_keeps_everything_under_limit() {
+        let mut buf = CaptureBuffer::new(5, 5);
+        buf.push("a".to_string());
+        buf.push("b".to_string());
+        assert_eq!(buf.dropped(), 0);
+        assert_eq!(buf.raw_lines(), vec!["a", "b"]);
+        assert_eq!(buf.dump(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn json_escape_escapes_control_and_quote() {
+        assert_eq!(json_escape("a\"b\\c"), "a\\\"b\\\\c");
+        assert_eq!(json_escape("line\nnext\ttab\r"), "line\\nnext\\ttab\\r");
+        assert_eq!(json_escape("\u{1}"), "\\u0001");
+    }
+
+    #[test]
+    fn json_array_renders_string_array() {
+        assert_eq!(json_array(&[]), "[]");
+        assert_eq!(
+            json_array(&["a".to_string(), "b\"c".to_string()]),
+            "[\"a\",\"b\\\"c\"]"
+        );
+    }
+}